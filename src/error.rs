@@ -0,0 +1,14 @@
+use alloc::string::String;
+
+use crate::utils::DecimalNumberParsingError;
+
+/// Error type for conversions and parsing that can fail while working with `UncGas`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UncGasError {
+    IncorrectNumber(DecimalNumberParsingError),
+    IncorrectUnit(String),
+    /// Returned by [`crate::GasMeter::charge`] when charging would overflow `u64` or push the
+    /// meter's `used` Gas past its `limit`.
+    GasLimitExceeded,
+}
@@ -0,0 +1,109 @@
+use crate::{UncGas, UncGasError};
+
+/// Tracks Gas consumption against a fixed limit, the way a VM accounts for execution cost.
+///
+/// Charging overflow is treated as a limit breach: `charge` returns
+/// [`UncGasError::GasLimitExceeded`] both when the addition would overflow `u64` and when it
+/// would merely push `used` past `limit`, so callers never need to separately check for overflow.
+///
+/// # Examples
+/// ```
+/// use unc_gas::{GasMeter, UncGas};
+///
+/// let mut meter = GasMeter::new(UncGas::from_gas(100));
+/// meter.charge(UncGas::from_gas(40)).unwrap();
+/// assert_eq!(meter.used(), UncGas::from_gas(40));
+/// assert_eq!(meter.remaining(), UncGas::from_gas(60));
+/// assert!(meter.charge(UncGas::from_gas(61)).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GasMeter {
+    limit: UncGas,
+    used: UncGas,
+}
+
+impl GasMeter {
+    /// Creates a new `GasMeter` with the given `limit` and no Gas used yet.
+    pub const fn new(limit: UncGas) -> Self {
+        Self {
+            limit,
+            used: UncGas::from_gas(0),
+        }
+    }
+
+    /// Charges `cost` against the meter.
+    ///
+    /// Returns [`UncGasError::GasLimitExceeded`] without mutating the meter if `used + cost`
+    /// overflows `u64` or exceeds `limit`.
+    pub const fn charge(&mut self, cost: UncGas) -> Result<(), UncGasError> {
+        match self.used.checked_add(cost) {
+            Some(used) if used.as_gas() <= self.limit.as_gas() => {
+                self.used = used;
+                Ok(())
+            }
+            _ => Err(UncGasError::GasLimitExceeded),
+        }
+    }
+
+    /// Returns the Gas limit configured for this meter.
+    pub const fn limit(&self) -> UncGas {
+        self.limit
+    }
+
+    /// Returns the total Gas charged against this meter so far.
+    pub const fn used(&self) -> UncGas {
+        self.used
+    }
+
+    /// Returns the Gas still available before hitting the limit.
+    pub const fn remaining(&self) -> UncGas {
+        self.limit.saturating_sub(self.used)
+    }
+
+    /// Refunds `amount` of previously charged Gas, saturating `used` at zero.
+    pub const fn refund(&mut self, amount: UncGas) {
+        self.used = self.used.saturating_sub(amount);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{GasMeter, UncGas, UncGasError};
+
+    #[test]
+    fn charge_within_limit() {
+        let mut meter = GasMeter::new(UncGas::from_gas(100));
+        assert_eq!(meter.charge(UncGas::from_gas(30)), Ok(()));
+        assert_eq!(meter.used(), UncGas::from_gas(30));
+        assert_eq!(meter.remaining(), UncGas::from_gas(70));
+    }
+
+    #[test]
+    fn charge_exceeding_limit() {
+        let mut meter = GasMeter::new(UncGas::from_gas(100));
+        assert_eq!(
+            meter.charge(UncGas::from_gas(101)),
+            Err(UncGasError::GasLimitExceeded)
+        );
+        assert_eq!(meter.used(), UncGas::from_gas(0));
+    }
+
+    #[test]
+    fn charge_overflow_is_limit_exceeded() {
+        let mut meter = GasMeter::new(UncGas::from_gas(u64::MAX));
+        meter.charge(UncGas::from_gas(u64::MAX - 1)).unwrap();
+        assert_eq!(
+            meter.charge(UncGas::from_gas(2)),
+            Err(UncGasError::GasLimitExceeded)
+        );
+    }
+
+    #[test]
+    fn refund_saturates_at_zero() {
+        let mut meter = GasMeter::new(UncGas::from_gas(100));
+        meter.charge(UncGas::from_gas(10)).unwrap();
+        meter.refund(UncGas::from_gas(50));
+        assert_eq!(meter.used(), UncGas::from_gas(0));
+        assert_eq!(meter.remaining(), UncGas::from_gas(100));
+    }
+}
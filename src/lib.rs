@@ -1,8 +1,12 @@
+#![no_std]
 //! A `UncGas` type to represent a value of Gas.
 //!
 //! Each `UncGas` is composed of a whole number of Gases.
 //! `UncGas` is implementing the common trait `FromStr`. Also, have utils function to parse from `str` into `u64`.
 //!
+//! This crate is `#![no_std]`, so `UncGas` can be used in on-chain/wasm contracts and other
+//! environments without the standard library.
+//!
 //! # Examples
 //! ```
 //! use unc_gas::*;
@@ -21,15 +25,35 @@
 //!   When enabled allows `UncGas` to serialized and deserialized by `serde`.
 //!
 //! * **schemars** (optional) -
-//!  Implements `schemars::JsonSchema` for `UncGas`.
+//!   Implements `schemars::JsonSchema` for `UncGas`.
 //!
 //! * **interactive-clap** (optional) -
-//!  Implements `interactive_clap::ToCli` for `UncGas`.
+//!   Implements `interactive_clap::ToCli` for `UncGas`.
+//!
+//! * **num-traits** (optional) -
+//!   Implements the `num-traits` crate's `Zero`, `One`, `Bounded`, checked-arithmetic, and
+//!   `Saturating` traits for `UncGas`, so it can be used in generic numeric code.
+//!
+//! * **arbitrary** (optional) -
+//!   Implements `arbitrary::Arbitrary` for `UncGas`, so it can be used by fuzzers and property-based tests.
+
+extern crate alloc;
+
+#[cfg(test)]
+extern crate std;
+
+// borsh::BorshSchema's derive expands to code that calls .to_string() on string literals; bring
+// ToString into scope here so that still resolves under #![no_std].
+#[cfg(feature = "abi")]
+use alloc::string::ToString;
+
 mod error;
+mod gas_meter;
 mod trait_impls;
 mod utils;
 
 pub use self::error::UncGasError;
+pub use self::gas_meter::GasMeter;
 pub use self::utils::DecimalNumberParsingError;
 
 #[derive(Default, Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
@@ -47,6 +71,12 @@ const ONE_TERA_GAS: u64 = 10u64.pow(12);
 const ONE_GIGA_GAS: u64 = 10u64.pow(9);
 
 impl UncGas {
+    /// The smallest value representable by `UncGas`.
+    pub const MIN: UncGas = UncGas::from_gas(u64::MIN);
+
+    /// The largest value representable by `UncGas`.
+    pub const MAX: UncGas = UncGas::from_gas(u64::MAX);
+
     /// Creates a new `UncGas` from the specified number of whole tera Gas.
     ///
     /// # Examples
@@ -243,6 +273,182 @@ impl UncGas {
         }
         UncGas::from_gas(self.as_gas().saturating_div(rhs))
     }
+
+    /// Calculates `self` + `rhs`, returning the result and whether an arithmetic overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_gas::UncGas;
+    /// assert_eq!(UncGas::from_gas(5).overflowing_add(UncGas::from_gas(2)), (UncGas::from_gas(7), false));
+    /// assert_eq!(UncGas::from_gas(u64::MAX).overflowing_add(UncGas::from_gas(1)), (UncGas::from_gas(0), true));
+    /// ```
+    pub const fn overflowing_add(self, rhs: UncGas) -> (UncGas, bool) {
+        let (gas, overflow) = self.as_gas().overflowing_add(rhs.as_gas());
+        (UncGas::from_gas(gas), overflow)
+    }
+
+    /// Calculates `self` - `rhs`, returning the result and whether an arithmetic overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_gas::UncGas;
+    /// assert_eq!(UncGas::from_gas(5).overflowing_sub(UncGas::from_gas(2)), (UncGas::from_gas(3), false));
+    /// assert_eq!(UncGas::from_gas(0).overflowing_sub(UncGas::from_gas(1)), (UncGas::from_gas(u64::MAX), true));
+    /// ```
+    pub const fn overflowing_sub(self, rhs: UncGas) -> (UncGas, bool) {
+        let (gas, overflow) = self.as_gas().overflowing_sub(rhs.as_gas());
+        (UncGas::from_gas(gas), overflow)
+    }
+
+    /// Calculates `self` * `rhs`, returning the result and whether an arithmetic overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_gas::UncGas;
+    /// assert_eq!(UncGas::from_gas(5).overflowing_mul(2), (UncGas::from_gas(10), false));
+    /// assert_eq!(UncGas::from_gas(u64::MAX).overflowing_mul(2), (UncGas::from_gas(u64::MAX - 1), true));
+    /// ```
+    pub const fn overflowing_mul(self, rhs: u64) -> (UncGas, bool) {
+        let (gas, overflow) = self.as_gas().overflowing_mul(rhs);
+        (UncGas::from_gas(gas), overflow)
+    }
+
+    /// Wrapping (modular) addition. Computes `self` + `rhs`, wrapping around at the boundary of `u64` instead of overflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_gas::UncGas;
+    /// assert_eq!(UncGas::from_gas(5).wrapping_add(UncGas::from_gas(2)), UncGas::from_gas(7));
+    /// assert_eq!(UncGas::from_gas(u64::MAX).wrapping_add(UncGas::from_gas(1)), UncGas::from_gas(0));
+    /// ```
+    pub const fn wrapping_add(self, rhs: UncGas) -> UncGas {
+        UncGas::from_gas(self.as_gas().wrapping_add(rhs.as_gas()))
+    }
+
+    /// Wrapping (modular) subtraction. Computes `self` - `rhs`, wrapping around at the boundary of `u64` instead of overflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_gas::UncGas;
+    /// assert_eq!(UncGas::from_gas(5).wrapping_sub(UncGas::from_gas(2)), UncGas::from_gas(3));
+    /// assert_eq!(UncGas::from_gas(0).wrapping_sub(UncGas::from_gas(1)), UncGas::from_gas(u64::MAX));
+    /// ```
+    pub const fn wrapping_sub(self, rhs: UncGas) -> UncGas {
+        UncGas::from_gas(self.as_gas().wrapping_sub(rhs.as_gas()))
+    }
+
+    /// Wrapping (modular) multiplication. Computes `self` * `rhs`, wrapping around at the boundary of `u64` instead of overflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_gas::UncGas;
+    /// assert_eq!(UncGas::from_gas(5).wrapping_mul(2), UncGas::from_gas(10));
+    /// assert_eq!(UncGas::from_gas(u64::MAX).wrapping_mul(2), UncGas::from_gas(u64::MAX - 1));
+    /// ```
+    pub const fn wrapping_mul(self, rhs: u64) -> UncGas {
+        UncGas::from_gas(self.as_gas().wrapping_mul(rhs))
+    }
+
+    /// Checked integer remainder. Computes `self` % `rhs`, returning `None` if `rhs == 0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_gas::UncGas;
+    /// assert_eq!(UncGas::from_gas(10).checked_rem(3), Some(UncGas::from_gas(1)));
+    /// assert_eq!(UncGas::from_gas(10).checked_rem(0), None);
+    /// ```
+    pub const fn checked_rem(self, rhs: u64) -> Option<Self> {
+        if let Some(gas) = self.as_gas().checked_rem(rhs) {
+            Some(Self::from_gas(gas))
+        } else {
+            None
+        }
+    }
+
+    /// Checked exponentiation. Computes `self.pow(exp)`, returning `None` if overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_gas::UncGas;
+    /// assert_eq!(UncGas::from_gas(2).checked_pow(10), Some(UncGas::from_gas(1024)));
+    /// assert_eq!(UncGas::from_gas(u64::MAX).checked_pow(2), None);
+    /// ```
+    pub const fn checked_pow(self, exp: u32) -> Option<Self> {
+        if let Some(gas) = self.as_gas().checked_pow(exp) {
+            Some(Self::from_gas(gas))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the memory representation of this `UncGas` as a byte array in little-endian order.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_gas::UncGas;
+    /// assert_eq!(UncGas::from_gas(5).to_le_bytes(), [5, 0, 0, 0, 0, 0, 0, 0]);
+    /// ```
+    pub const fn to_le_bytes(self) -> [u8; 8] {
+        self.as_gas().to_le_bytes()
+    }
+
+    /// Creates a `UncGas` from its memory representation as a byte array in little-endian order.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_gas::UncGas;
+    /// assert_eq!(UncGas::from_le_bytes([5, 0, 0, 0, 0, 0, 0, 0]), UncGas::from_gas(5));
+    /// ```
+    pub const fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        Self::from_gas(u64::from_le_bytes(bytes))
+    }
+
+    /// Returns the memory representation of this `UncGas` as a byte array in big-endian order.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_gas::UncGas;
+    /// assert_eq!(UncGas::from_gas(5).to_be_bytes(), [0, 0, 0, 0, 0, 0, 0, 5]);
+    /// ```
+    pub const fn to_be_bytes(self) -> [u8; 8] {
+        self.as_gas().to_be_bytes()
+    }
+
+    /// Creates a `UncGas` from its memory representation as a byte array in big-endian order.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_gas::UncGas;
+    /// assert_eq!(UncGas::from_be_bytes([0, 0, 0, 0, 0, 0, 0, 5]), UncGas::from_gas(5));
+    /// ```
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        Self::from_gas(u64::from_be_bytes(bytes))
+    }
+
+    /// Decomposes this `UncGas` into two 32-bit limbs, ordered `[low, high]`, the way a circuit
+    /// keeping each limb within a field element would split a 64-bit Gas counter.
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_gas::UncGas;
+    /// assert_eq!(UncGas::from_gas(u32::MAX as u64 + 1).to_le_limbs(), [0, 1]);
+    /// ```
+    pub const fn to_le_limbs(self) -> [u32; 2] {
+        let gas = self.as_gas();
+        [gas as u32, (gas >> 32) as u32]
+    }
+
+    /// Recomposes a `UncGas` from two 32-bit limbs ordered `[low, high]`, the inverse of
+    /// [`UncGas::to_le_limbs`].
+    ///
+    /// # Examples
+    /// ```
+    /// use unc_gas::UncGas;
+    /// assert_eq!(UncGas::from_le_limbs([0, 1]), UncGas::from_gas(u32::MAX as u64 + 1));
+    /// ```
+    pub const fn from_le_limbs(limbs: [u32; 2]) -> Self {
+        Self::from_gas((limbs[0] as u64) | ((limbs[1] as u64) << 32))
+    }
 }
 
 #[cfg(test)]
@@ -329,4 +535,109 @@ mod test {
         assert_eq!(gas.saturating_div(rhs), UncGas::from_gas(5));
         assert_eq!(gas.saturating_div(another_gas), UncGas::from_gas(0));
     }
+
+    #[test]
+    fn overflowing_add_gas() {
+        let gas = UncGas::from_gas(u64::MAX - 3);
+        assert_eq!(
+            gas.overflowing_add(UncGas::from_gas(3)),
+            (UncGas::from_gas(u64::MAX), false)
+        );
+        assert_eq!(
+            gas.overflowing_add(UncGas::from_gas(4)),
+            (UncGas::from_gas(0), true)
+        );
+    }
+
+    #[test]
+    fn overflowing_sub_gas() {
+        let gas = UncGas::from_gas(3);
+        assert_eq!(
+            gas.overflowing_sub(UncGas::from_gas(1)),
+            (UncGas::from_gas(2), false)
+        );
+        assert_eq!(
+            gas.overflowing_sub(UncGas::from_gas(4)),
+            (UncGas::from_gas(u64::MAX), true)
+        );
+    }
+
+    #[test]
+    fn overflowing_mul_gas() {
+        let gas = UncGas::from_gas(u64::MAX / 10);
+        assert_eq!(
+            gas.overflowing_mul(10),
+            (UncGas::from_gas(u64::MAX / 10 * 10), false)
+        );
+        assert!(gas.overflowing_mul(11).1);
+    }
+
+    #[test]
+    fn wrapping_add_gas() {
+        let gas = UncGas::from_gas(u64::MAX - 3);
+        assert_eq!(
+            gas.wrapping_add(UncGas::from_gas(3)),
+            UncGas::from_gas(u64::MAX)
+        );
+        assert_eq!(gas.wrapping_add(UncGas::from_gas(4)), UncGas::from_gas(0));
+    }
+
+    #[test]
+    fn wrapping_sub_gas() {
+        let gas = UncGas::from_gas(3);
+        assert_eq!(
+            gas.wrapping_sub(UncGas::from_gas(1)),
+            UncGas::from_gas(2)
+        );
+        assert_eq!(
+            gas.wrapping_sub(UncGas::from_gas(4)),
+            UncGas::from_gas(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn wrapping_mul_gas() {
+        let gas = UncGas::from_gas(u64::MAX / 10);
+        assert_eq!(gas.wrapping_mul(10), UncGas::from_gas(u64::MAX / 10 * 10));
+        assert_eq!(
+            UncGas::from_gas(u64::MAX).wrapping_mul(2),
+            UncGas::from_gas(u64::MAX - 1)
+        );
+    }
+
+    #[test]
+    fn checked_rem_gas() {
+        let gas = UncGas::from_gas(10);
+        assert_eq!(gas.checked_rem(3), Some(UncGas::from_gas(1)));
+        assert_eq!(gas.checked_rem(0), None);
+    }
+
+    #[test]
+    fn checked_pow_gas() {
+        let gas = UncGas::from_gas(2);
+        assert_eq!(gas.checked_pow(10), Some(UncGas::from_gas(1024)));
+        assert_eq!(UncGas::from_gas(u64::MAX).checked_pow(2), None);
+    }
+
+    #[test]
+    fn le_bytes_gas() {
+        let gas = UncGas::from_gas(0x0102_0304_0506_0708);
+        assert_eq!(gas.to_le_bytes(), [8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(UncGas::from_le_bytes(gas.to_le_bytes()), gas);
+    }
+
+    #[test]
+    fn be_bytes_gas() {
+        let gas = UncGas::from_gas(0x0102_0304_0506_0708);
+        assert_eq!(gas.to_be_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(UncGas::from_be_bytes(gas.to_be_bytes()), gas);
+    }
+
+    #[test]
+    fn le_limbs_gas() {
+        let gas = UncGas::from_gas(u32::MAX as u64 + 1);
+        assert_eq!(gas.to_le_limbs(), [0, 1]);
+        assert_eq!(UncGas::from_le_limbs(gas.to_le_limbs()), gas);
+        assert_eq!(UncGas::from_le_limbs([5, 0]), UncGas::from_gas(5));
+    }
 }
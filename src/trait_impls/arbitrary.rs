@@ -0,0 +1,13 @@
+use arbitrary::{Arbitrary, Unstructured};
+
+use crate::UncGas;
+
+impl<'a> Arbitrary<'a> for UncGas {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(UncGas::from_gas(u64::arbitrary(u)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u64::size_hint(depth)
+    }
+}
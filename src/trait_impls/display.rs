@@ -6,8 +6,8 @@ use crate::{UncGas, UncGasError, ONE_GIGA_GAS};
 /// 2. <0.001 Tgas
 /// 3. 0.001 - 0.999 Tgas (uses 3 digits after the floating point)
 /// 4. >1 Tgas (uses 1 digit after the floating point)
-impl std::fmt::Display for UncGas {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for UncGas {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if *self == UncGas::from_gas(0) {
             write!(f, "0 Tgas")
         } else if *self < UncGas::from_ggas(1) {
@@ -28,17 +28,20 @@ impl std::fmt::Display for UncGas {
     }
 }
 
-impl std::fmt::Display for UncGasError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for UncGasError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             UncGasError::IncorrectNumber(err) => write!(f, "Incorrect number: {:?}", err),
             UncGasError::IncorrectUnit(err) => write!(f, "Incorrect unit: {}", err),
+            UncGasError::GasLimitExceeded => write!(f, "Gas limit exceeded"),
         }
     }
 }
 
 #[cfg(test)]
 mod test {
+    use alloc::string::ToString;
+
     use crate::UncGas;
 
     #[test]
@@ -0,0 +1,60 @@
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod display;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+#[cfg(all(test, feature = "arbitrary"))]
+mod property_tests;
+
+use alloc::borrow::ToOwned;
+use core::str::FromStr;
+
+use crate::{
+    utils::parse_decimal_number,
+    UncGas, UncGasError,
+};
+
+/// Parses strings of the form `"<number> <unit>"` (e.g. `"5 Tgas"`, `"1.5 ggas"`, `"12345 gas"`)
+/// into a `UncGas`. Every `Display` output parses back except the `<0.001 Tgas` breakpoint,
+/// which is rejected as an incorrect unit.
+impl FromStr for UncGas {
+    type Err = UncGasError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let split_at = s
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .ok_or_else(|| UncGasError::IncorrectUnit(s.to_owned()))?;
+        let (number, unit) = s.split_at(split_at);
+        let gas = match unit.trim().to_ascii_lowercase().as_str() {
+            "tgas" | "tera" => {
+                parse_decimal_number(number, 12).map_err(UncGasError::IncorrectNumber)?
+            }
+            "ggas" | "giga" => {
+                parse_decimal_number(number, 9).map_err(UncGasError::IncorrectNumber)?
+            }
+            "gas" => parse_decimal_number(number, 0).map_err(UncGasError::IncorrectNumber)?,
+            _ => return Err(UncGasError::IncorrectUnit(s.to_owned())),
+        };
+        Ok(UncGas::from_gas(gas))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::str::FromStr;
+
+    use crate::UncGas;
+
+    #[test]
+    fn parses_known_units() {
+        assert_eq!(UncGas::from_str("5 Tgas").unwrap(), UncGas::from_tgas(5));
+        assert_eq!(UncGas::from_str("1.5 ggas").unwrap(), UncGas::from_gas(1_500_000_000));
+        assert_eq!(UncGas::from_str("12345 gas").unwrap(), UncGas::from_gas(12345));
+    }
+
+    #[test]
+    fn rejects_unknown_unit() {
+        assert!(UncGas::from_str("5 foogas").is_err());
+    }
+}
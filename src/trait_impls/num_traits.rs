@@ -0,0 +1,167 @@
+//! Implements the `num-traits` crate's numeric traits for `UncGas`, forwarding to the inherent
+//! checked/saturating methods so `UncGas` can be used in generic numeric code.
+
+use core::ops::{Add, Div, Mul, Sub};
+
+use num_traits::{Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, One, Saturating, Zero};
+
+use crate::UncGas;
+
+// `num_traits::Zero`/`One`/`Checked*` require the corresponding `core::ops` trait as a supertrait.
+// Like `checked_div`, `saturating_div` and `GasMeter::charge`, which return rather than
+// panicking on bad input, these forward to the existing
+// saturating methods instead of overflowing/dividing by zero.
+
+impl Add for UncGas {
+    type Output = Self;
+
+    /// Saturates at [`UncGas::MAX`] instead of overflowing. See [`UncGas::saturating_add`].
+    fn add(self, rhs: Self) -> Self::Output {
+        self.saturating_add(rhs)
+    }
+}
+
+impl Sub for UncGas {
+    type Output = Self;
+
+    /// Saturates at [`UncGas::MIN`] instead of overflowing. See [`UncGas::saturating_sub`].
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.saturating_sub(rhs)
+    }
+}
+
+impl Mul for UncGas {
+    type Output = Self;
+
+    /// Saturates at [`UncGas::MAX`] instead of overflowing. See [`UncGas::saturating_mul`].
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.saturating_mul(rhs.as_gas())
+    }
+}
+
+impl Div for UncGas {
+    type Output = Self;
+
+    /// Returns zero for division by zero instead of panicking. See [`UncGas::saturating_div`].
+    fn div(self, rhs: Self) -> Self::Output {
+        self.saturating_div(rhs.as_gas())
+    }
+}
+
+impl Zero for UncGas {
+    fn zero() -> Self {
+        UncGas::from_gas(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.as_gas() == 0
+    }
+}
+
+/// One Gas.
+impl One for UncGas {
+    fn one() -> Self {
+        UncGas::from_gas(1)
+    }
+}
+
+impl Bounded for UncGas {
+    fn min_value() -> Self {
+        UncGas::from_gas(u64::MIN)
+    }
+
+    fn max_value() -> Self {
+        UncGas::from_gas(u64::MAX)
+    }
+}
+
+impl CheckedAdd for UncGas {
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        UncGas::checked_add(*self, *rhs)
+    }
+}
+
+impl CheckedSub for UncGas {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        UncGas::checked_sub(*self, *rhs)
+    }
+}
+
+impl CheckedMul for UncGas {
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        UncGas::checked_mul(*self, rhs.as_gas())
+    }
+}
+
+impl CheckedDiv for UncGas {
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        UncGas::checked_div(*self, rhs.as_gas())
+    }
+}
+
+impl Saturating for UncGas {
+    fn saturating_add(self, rhs: Self) -> Self {
+        UncGas::saturating_add(self, rhs)
+    }
+
+    fn saturating_sub(self, rhs: Self) -> Self {
+        UncGas::saturating_sub(self, rhs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_traits::{Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, One, Zero};
+
+    use crate::UncGas;
+
+    #[test]
+    fn zero_and_one() {
+        assert!(UncGas::zero().is_zero());
+        assert_eq!(UncGas::one(), UncGas::from_gas(1));
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(UncGas::min_value(), UncGas::from_gas(u64::MIN));
+        assert_eq!(UncGas::max_value(), UncGas::from_gas(u64::MAX));
+    }
+
+    #[test]
+    fn ops_saturate_instead_of_panicking() {
+        assert_eq!(
+            UncGas::from_gas(u64::MAX) + UncGas::from_gas(1),
+            UncGas::from_gas(u64::MAX)
+        );
+        assert_eq!(
+            UncGas::from_gas(0) - UncGas::from_gas(1),
+            UncGas::from_gas(0)
+        );
+        assert_eq!(
+            UncGas::from_gas(u64::MAX) * UncGas::from_gas(2),
+            UncGas::from_gas(u64::MAX)
+        );
+        assert_eq!(
+            UncGas::from_gas(10) / UncGas::from_gas(0),
+            UncGas::from_gas(0)
+        );
+    }
+
+    #[test]
+    fn checked_ops_forward_to_inherent_methods() {
+        let gas = UncGas::from_gas(10);
+        assert_eq!(
+            CheckedAdd::checked_add(&gas, &UncGas::from_gas(5)),
+            Some(UncGas::from_gas(15))
+        );
+        assert_eq!(CheckedSub::checked_sub(&gas, &UncGas::from_gas(20)), None);
+        assert_eq!(
+            CheckedMul::checked_mul(&gas, &UncGas::from_gas(2)),
+            Some(UncGas::from_gas(20))
+        );
+        assert_eq!(
+            CheckedDiv::checked_div(&gas, &UncGas::from_gas(0)),
+            None
+        );
+    }
+}
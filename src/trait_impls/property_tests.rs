@@ -0,0 +1,56 @@
+//! Property-based round-trip tests for `UncGas` parsing and `Display`, compiled only when the
+//! `arbitrary` feature is enabled.
+
+use alloc::string::ToString;
+use core::str::FromStr;
+
+use proptest::prelude::*;
+
+use crate::UncGas;
+
+fn tgas_value(display: &str) -> f64 {
+    display
+        .trim_end_matches(" Tgas")
+        .parse()
+        .expect("a `>1 Tgas` Display output must be a plain decimal number")
+}
+
+proptest! {
+    #[test]
+    fn from_gas_as_gas_roundtrip(x: u64) {
+        prop_assert_eq!(UncGas::from_gas(x).as_gas(), x);
+    }
+
+    /// The `Display` impl documents exactly four breakpoints; every `u64` must land in one of
+    /// them and never panic while formatting.
+    #[test]
+    fn display_never_panics_and_matches_one_breakpoint(x: u64) {
+        let display = UncGas::from_gas(x).to_string();
+        let is_zero = display == "0 Tgas";
+        let is_sub_ggas = display == "<0.001 Tgas";
+        let is_ggas = display.starts_with("0.") && !is_sub_ggas;
+        let is_tgas_or_more = !is_zero && !is_sub_ggas && !is_ggas && display.ends_with(" Tgas");
+        prop_assert!(is_zero || is_sub_ggas || is_ggas || is_tgas_or_more);
+    }
+
+    /// Whenever `Display` produces a machine-parseable form (i.e. not the `<0.001 Tgas`
+    /// breakpoint), `FromStr` must parse it back into a `UncGas` whose own `Display` is stable.
+    #[test]
+    fn from_str_of_display_round_trips(x: u64) {
+        let display = UncGas::from_gas(x).to_string();
+        if display.starts_with('<') {
+            return Ok(());
+        }
+        let reparsed = UncGas::from_str(&display).expect("display output must parse back");
+        prop_assert_eq!(reparsed.to_string(), display);
+    }
+
+    /// In the `>1 Tgas` branch, a larger Gas value never displays a smaller Tgas number.
+    #[test]
+    fn tgas_rounding_is_monotonic(a in crate::ONE_TERA_GAS.., b in crate::ONE_TERA_GAS..) {
+        let (smaller, larger) = if a <= b { (a, b) } else { (b, a) };
+        let smaller_tgas = tgas_value(&UncGas::from_gas(smaller).to_string());
+        let larger_tgas = tgas_value(&UncGas::from_gas(larger).to_string());
+        prop_assert!(smaller_tgas <= larger_tgas);
+    }
+}
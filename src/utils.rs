@@ -0,0 +1,80 @@
+use alloc::{borrow::ToOwned, string::String};
+use core::fmt;
+
+/// Error type for parsing a decimal number (optionally with a fractional part) out of a string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DecimalNumberParsingError {
+    InvalidNumber(String),
+    LongWhole(String),
+    LongFractional(String),
+}
+
+impl fmt::Display for DecimalNumberParsingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidNumber(number) => write!(f, "invalid number: {}", number),
+            Self::LongWhole(whole) => write!(f, "too long whole part: {}", whole),
+            Self::LongFractional(fractional) => {
+                write!(f, "too long fractional part: {}", fractional)
+            }
+        }
+    }
+}
+
+/// Parses a decimal number like `"1.5"` into the integer number of the smallest unit, given that
+/// the smallest unit is `10.pow(fraction_digits)` per whole unit, e.g.
+/// `parse_decimal_number("1.5", 12)` returns `1_500_000_000_000`.
+pub(crate) fn parse_decimal_number(
+    s: &str,
+    fraction_digits: u32,
+) -> Result<u64, DecimalNumberParsingError> {
+    let (whole, fractional) = match s.split_once('.') {
+        Some((whole, fractional)) => (whole, fractional),
+        None => (s, ""),
+    };
+    let whole: u64 = whole
+        .parse()
+        .map_err(|_| DecimalNumberParsingError::InvalidNumber(s.to_owned()))?;
+    let fractional_digits = fractional.len() as u32;
+    if fractional_digits > fraction_digits {
+        return Err(DecimalNumberParsingError::LongFractional(s.to_owned()));
+    }
+    let fractional: u64 = if fractional.is_empty() {
+        0
+    } else {
+        fractional
+            .parse()
+            .map_err(|_| DecimalNumberParsingError::InvalidNumber(s.to_owned()))?
+    };
+
+    let pow = 10u64
+        .checked_pow(fraction_digits)
+        .ok_or_else(|| DecimalNumberParsingError::LongWhole(s.to_owned()))?;
+    let whole = whole
+        .checked_mul(pow)
+        .ok_or_else(|| DecimalNumberParsingError::LongWhole(s.to_owned()))?;
+    let scaled_fractional = fractional * 10u64.pow(fraction_digits - fractional_digits);
+
+    whole
+        .checked_add(scaled_fractional)
+        .ok_or_else(|| DecimalNumberParsingError::LongWhole(s.to_owned()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_decimal_number;
+
+    #[test]
+    fn parses_whole_and_fractional() {
+        assert_eq!(parse_decimal_number("5", 12), Ok(5_000_000_000_000));
+        assert_eq!(parse_decimal_number("1.5", 12), Ok(1_500_000_000_000));
+        assert_eq!(parse_decimal_number("0.000001", 12), Ok(1_000_000));
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        assert!(parse_decimal_number("abc", 12).is_err());
+        assert!(parse_decimal_number("1.0000000000001", 12).is_err());
+    }
+}